@@ -1,6 +1,9 @@
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::ops::{Deref, DerefMut};
 
 pub trait At<const I: usize> {
@@ -41,6 +44,42 @@ pub mod or0 {
     }
 }
 
+#[cfg(feature = "either")]
+pub mod either {
+    use crate::or2::Or;
+    use ::either::Either;
+
+    impl<T0, T1> From<Either<T0, T1>> for Or<T0, T1> {
+        #[inline]
+        fn from(value: Either<T0, T1>) -> Self {
+            match value {
+                Either::Left(item) => Self::T0(item),
+                Either::Right(item) => Self::T1(item),
+            }
+        }
+    }
+
+    impl<T0, T1> From<Or<T0, T1>> for Either<T0, T1> {
+        #[inline]
+        fn from(value: Or<T0, T1>) -> Self {
+            match value {
+                Or::T0(item) => Self::Left(item),
+                Or::T1(item) => Self::Right(item),
+            }
+        }
+    }
+
+    impl<T, I: IntoIterator<Item = T>, J: IntoIterator<Item = T>> Or<I, J> {
+        #[inline]
+        pub fn factor_into_iter(self) -> impl Iterator<Item = T> {
+            match self {
+                Self::T0(item) => Either::Left(item.into_iter()),
+                Self::T1(item) => Either::Right(item.into_iter()),
+            }
+        }
+    }
+}
+
 macro_rules! or {
     (
         [$($count: tt, $alias: ident, $module: ident),* $(,)?]
@@ -204,6 +243,24 @@ macro_rules! or {
                 }
             }
 
+            impl<$($t: core::fmt::Display,)*> core::fmt::Display for Or<$($t,)*> {
+                #[inline]
+                fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self {
+                        $(Self::$t(item) => core::fmt::Display::fmt(item, formatter),)*
+                    }
+                }
+            }
+
+            impl<$($t: core::error::Error,)*> core::error::Error for Or<$($t,)*> {
+                #[inline]
+                fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+                    match self {
+                        $(Self::$t(item) => item.source(),)*
+                    }
+                }
+            }
+
             #[cfg(feature = "iter")]
             pub mod iter {
                 use super::Or;
@@ -222,14 +279,13 @@ macro_rules! or {
                     pub fn iter_mut(&mut self) -> Iterator<$(<&mut $t as IntoIterator>::IntoIter,)*> where $(for<'a> &'a mut $t: IntoIterator,)* {
                         self.as_mut().into_iter()
                     }
-                }
-
-                impl<$($t: IntoIterator),*> IntoIterator for Or<$($t,)*> {
-                    type IntoIter = Iterator<$($t::IntoIter,)*>;
-                    type Item = Or<$($t::Item,)*>;
 
+                    // An inherent method rather than an `IntoIterator` impl so it does not
+                    // overlap with the blanket `IntoIterator` that `core` grants to any type
+                    // implementing `core::iter::Iterator` directly (see below).
                     #[inline]
-                    fn into_iter(self) -> Self::IntoIter {
+                    #[allow(clippy::should_implement_trait)]
+                    pub fn into_iter(self) -> Iterator<$($t::IntoIter,)*> where $($t: IntoIterator,)* {
                         match self {
                             $(Self::$t(item) => Iterator::$t(item.into_iter()),)*
                         }
@@ -275,6 +331,44 @@ macro_rules! or {
                         }
                     }
                 }
+
+                impl<T, $($t: core::iter::Iterator<Item = T>,)*> core::iter::Iterator for Or<$($t,)*> {
+                    type Item = T;
+
+                    #[inline]
+                    fn next(&mut self) -> Option<T> {
+                        match self {
+                            $(Self::$t(item) => item.next(),)*
+                        }
+                    }
+
+                    #[inline]
+                    fn size_hint(&self) -> (usize, Option<usize>) {
+                        match self {
+                            $(Self::$t(item) => item.size_hint(),)*
+                        }
+                    }
+                }
+
+                impl<T, $($t: DoubleEndedIterator<Item = T>,)*> DoubleEndedIterator for Or<$($t,)*> {
+                    #[inline]
+                    fn next_back(&mut self) -> Option<T> {
+                        match self {
+                            $(Self::$t(item) => item.next_back(),)*
+                        }
+                    }
+                }
+
+                impl<T, $($t: ExactSizeIterator<Item = T>,)*> ExactSizeIterator for Or<$($t,)*> {
+                    #[inline]
+                    fn len(&self) -> usize {
+                        match self {
+                            $(Self::$t(item) => item.len(),)*
+                        }
+                    }
+                }
+
+                impl<T, $($t: FusedIterator<Item = T>,)*> FusedIterator for Or<$($t,)*> { }
             }
 
             #[cfg(feature = "rayon")]
@@ -419,6 +513,90 @@ macro_rules! or {
                 or!(@rayon @outer [$($t),*] [$($index, $t),*]);
             }
 
+            #[cfg(feature = "stream")]
+            pub mod stream {
+                use super::Or;
+                use core::{
+                    pin::Pin,
+                    task::{Context, Poll},
+                };
+                use futures_core::Stream;
+
+                impl<$($t: Stream + Unpin,)*> Stream for Or<$($t,)*> {
+                    type Item = Or<$($t::Item,)*>;
+
+                    #[inline]
+                    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                        match &mut *self {
+                            $(Self::$t(item) => Pin::new(item).poll_next(cx).map(|item| item.map(Or::$t)),)*
+                        }
+                    }
+
+                    #[inline]
+                    fn size_hint(&self) -> (usize, Option<usize>) {
+                        match self {
+                            $(Self::$t(item) => item.size_hint(),)*
+                        }
+                    }
+                }
+            }
+
+            #[cfg(feature = "std")]
+            pub mod io {
+                use super::Or;
+                use std::io::{BufRead, Read, Result, Seek, SeekFrom, Write};
+
+                impl<$($t: Read,)*> Read for Or<$($t,)*> {
+                    #[inline]
+                    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                        match self {
+                            $(Self::$t(item) => item.read(buf),)*
+                        }
+                    }
+                }
+
+                impl<$($t: BufRead,)*> BufRead for Or<$($t,)*> {
+                    #[inline]
+                    fn fill_buf(&mut self) -> Result<&[u8]> {
+                        match self {
+                            $(Self::$t(item) => item.fill_buf(),)*
+                        }
+                    }
+
+                    #[inline]
+                    fn consume(&mut self, amount: usize) {
+                        match self {
+                            $(Self::$t(item) => item.consume(amount),)*
+                        }
+                    }
+                }
+
+                impl<$($t: Write,)*> Write for Or<$($t,)*> {
+                    #[inline]
+                    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                        match self {
+                            $(Self::$t(item) => item.write(buf),)*
+                        }
+                    }
+
+                    #[inline]
+                    fn flush(&mut self) -> Result<()> {
+                        match self {
+                            $(Self::$t(item) => item.flush(),)*
+                        }
+                    }
+                }
+
+                impl<$($t: Seek,)*> Seek for Or<$($t,)*> {
+                    #[inline]
+                    fn seek(&mut self, position: SeekFrom) -> Result<u64> {
+                        match self {
+                            $(Self::$t(item) => item.seek(position),)*
+                        }
+                    }
+                }
+            }
+
             or!(@outer [$($index, $t, $get, $is, $map),*] []);
         }
     };
@@ -631,3 +809,59 @@ or!(
         15, T15, U15, F15, t15, is_t15, map_t15,
     ]
 );
+
+macro_rules! widen {
+    ([$($count: tt, $module: ident),* $(,)?] [$($t: ident),* $(,)?]) => {
+        widen!(@step [$($count, $module),*] [$($t),*] [] []);
+    };
+    (@step [] $ts: tt $old: tt $seen: tt) => {};
+    (@step
+        [$count: tt, $module: ident $(, $counts: tt, $modules: ident)*]
+        [$t: ident $(, $rest: ident)*]
+        [$($old_t: ident),*]
+        [$($seen_count: tt, $seen_module: ident, [$($seen_t: ident),*]),*]
+    ) => {
+        widen!(@pairs $count, $module, [$($old_t,)* $t] [$($seen_count, $seen_module, [$($seen_t),*]),*]);
+        widen!(@step
+            [$($counts, $modules),*]
+            [$($rest),*]
+            [$($old_t,)* $t]
+            [$($seen_count, $seen_module, [$($seen_t),*],)* $count, $module, [$($old_t,)* $t]]
+        );
+    };
+    (@pairs $n: tt, $module_n: ident, $tn: tt [$($seen_count: tt, $seen_module: ident, $seen_tm: tt),*]) => {
+        $(widen!(@pair $seen_count, $seen_module, $seen_tm, $n, $module_n, $tn);)*
+    };
+    (@pair $m: tt, $module_m: ident, [$($tm: ident),*], $n: tt, $module_n: ident, [$($tn: ident),*]) => {
+        impl<$($tn),*> From<$module_m::Or<$($tm),*>> for $module_n::Or<$($tn),*> {
+            #[inline]
+            fn from(value: $module_m::Or<$($tm),*>) -> Self {
+                match value {
+                    $($module_m::Or::$tm(item) => Self::$tm(item),)*
+                }
+            }
+        }
+
+        impl<$($tn),*> TryFrom<$module_n::Or<$($tn),*>> for $module_m::Or<$($tm),*> {
+            type Error = $module_n::Or<$($tn),*>;
+
+            #[inline]
+            fn try_from(value: $module_n::Or<$($tn),*>) -> Result<Self, Self::Error> {
+                match value {
+                    $($module_n::Or::$tm(item) => Ok(Self::$tm(item)),)*
+                    other => Err(other),
+                }
+            }
+        }
+    };
+}
+
+widen!(
+    [
+        1, or1, 2, or2, 3, or3, 4, or4, 5, or5, 6, or6, 7, or7, 8, or8, 9, or9, 10, or10, 11,
+        or11, 12, or12, 13, or13, 14, or14, 15, or15, 16, or16,
+    ]
+    [
+        T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15,
+    ]
+);
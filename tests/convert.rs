@@ -0,0 +1,15 @@
+use orn::{Or2, Or4};
+
+#[test]
+fn widens_and_narrows() {
+    let small = Or2::<char, u8>::T1(1u8);
+    let big = Or4::<char, u8, bool, &'static str>::from(small);
+    assert_eq!(big, Or4::T1(1u8));
+
+    let narrowed: Result<Or2<char, u8>, _> = Or4::<char, u8, bool, &'static str>::T1(1u8).try_into();
+    assert_eq!(narrowed, Ok(Or2::T1(1u8)));
+
+    let unrepresentable = Or4::<char, u8, bool, &'static str>::T2(true);
+    let narrowed: Result<Or2<char, u8>, _> = unrepresentable.try_into();
+    assert_eq!(narrowed, Err(Or4::T2(true)));
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "either")]
+
+use either::Either;
+use orn::Or2;
+
+#[test]
+fn compiles() {
+    let left = Either::<char, char>::from(or('a'));
+    assert_eq!(left, Either::Left('a'));
+    assert_eq!(Or2::from(left), or('a'));
+    assert_eq!(
+        or(vec!['a']).factor_into_iter().collect::<Vec<_>>(),
+        vec!['a']
+    );
+}
+
+const fn or<T>(value: T) -> Or2<T, T> {
+    Or2::T0(value)
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "std")]
+
+use orn::Or2;
+use std::io::{Cursor, Read, Write};
+
+#[test]
+fn compiles() {
+    let mut reader = or(Cursor::new(vec![1u8, 2, 3]));
+    let mut buffer = [0u8; 3];
+    reader.read_exact(&mut buffer).unwrap();
+    assert_eq!(buffer, [1, 2, 3]);
+
+    let mut writer = or(Cursor::new(Vec::new()));
+    writer.write_all(&[4u8, 5, 6]).unwrap();
+    writer.flush().unwrap();
+}
+
+const fn or<T>(value: T) -> Or2<T, T> {
+    Or2::T0(value)
+}
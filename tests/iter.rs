@@ -10,6 +10,12 @@ fn compiles() {
     or(vec!['a']).extend(['a']);
 }
 
+#[test]
+fn iterates_directly() {
+    let items = or(vec!['a'].into_iter());
+    assert_eq!(items.collect::<Vec<_>>(), vec!['a']);
+}
+
 const fn or<T>(value: T) -> Or2<T, T> {
     Or2::T0(value)
 }
@@ -1,8 +1,29 @@
 use orn::Or2;
-use std::borrow::Cow;
+use std::{borrow::Cow, error::Error, num::ParseIntError};
 
 #[test]
 fn into_compiles() {
     let value = Or2::<&'static str, Cow<'static, str>>::T0("a").into::<String>();
     assert_eq!(value, "a".to_string());
 }
+
+#[derive(Debug)]
+struct Custom;
+
+impl core::fmt::Display for Custom {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "custom")
+    }
+}
+
+impl Error for Custom {}
+
+#[test]
+fn displays_and_errors() {
+    let parse: Or2<ParseIntError, Custom> = Or2::T0("a".parse::<u8>().unwrap_err());
+    assert_eq!(parse.to_string(), "invalid digit found in string");
+    assert!(parse.source().is_none());
+
+    let custom: Or2<ParseIntError, Custom> = Or2::T1(Custom);
+    assert_eq!(custom.to_string(), "custom");
+}
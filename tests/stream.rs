@@ -0,0 +1,33 @@
+#![cfg(feature = "stream")]
+
+use core::{
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+use futures_core::Stream;
+use orn::Or2;
+
+struct Once(Option<char>);
+
+impl Stream for Once {
+    type Item = char;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<char>> {
+        Poll::Ready(self.0.take())
+    }
+}
+
+#[test]
+fn compiles() {
+    let mut stream = pin!(or(Once(Some('a'))));
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(waker);
+    match stream.as_mut().poll_next(&mut cx) {
+        Poll::Ready(value) => assert_eq!(value.map(|value| value.into::<char>()), Some('a')),
+        Poll::Pending => panic!("expected a ready value"),
+    }
+}
+
+const fn or<T>(value: T) -> Or2<T, T> {
+    Or2::T0(value)
+}